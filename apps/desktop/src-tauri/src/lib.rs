@@ -1,17 +1,34 @@
 use base64::{engine::general_purpose::STANDARD, Engine};
+use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
 use tauri::command;
+use tauri::ipc::Channel;
 
 /// TTS 请求参数
 #[derive(Debug, Deserialize)]
 pub struct TtsRequest {
     pub text: String,
     pub api_key: String,
+    #[serde(default)]
     pub reference_id: String,
     #[serde(default = "default_model")]
     pub model: String,
     #[serde(default = "default_format")]
     pub format: String,
+    #[serde(default = "default_max_len")]
+    pub max_len: usize,
+    #[serde(default)]
+    pub provider: TtsProvider,
+    #[serde(default)]
+    pub voice: Voice,
+    #[serde(default)]
+    pub connect_timeout_ms: Option<u64>,
+    #[serde(default)]
+    pub read_timeout_ms: Option<u64>,
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+    #[serde(default)]
+    pub proxy: Option<String>,
 }
 
 fn default_model() -> String {
@@ -22,6 +39,119 @@ fn default_format() -> String {
     "mp3".to_string()
 }
 
+fn default_max_len() -> usize {
+    2000
+}
+
+/// 支持的 TTS 后端
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TtsProvider {
+    #[default]
+    FishAudio,
+    OpenAI,
+}
+
+/// OpenAI `tts-1` 支持的内置音色
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Voice {
+    #[default]
+    Alloy,
+    Echo,
+    Fable,
+    Onyx,
+    Nova,
+    Shimmer,
+}
+
+impl Voice {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Voice::Alloy => "alloy",
+            Voice::Echo => "echo",
+            Voice::Fable => "fable",
+            Voice::Onyx => "onyx",
+            Voice::Nova => "nova",
+            Voice::Shimmer => "shimmer",
+        }
+    }
+}
+
+/// 将 provider 映射到对应的接口地址、鉴权方式与请求体
+trait TtsProviderConfig {
+    fn endpoint(&self) -> &'static str;
+    fn auth_header(&self, api_key: &str) -> (&'static str, String);
+    fn body(&self, text: &str, request: &TtsRequest) -> serde_json::Value;
+}
+
+impl TtsProviderConfig for TtsProvider {
+    fn endpoint(&self) -> &'static str {
+        match self {
+            TtsProvider::FishAudio => "https://api.fish.audio/v1/tts",
+            TtsProvider::OpenAI => "https://api.openai.com/v1/audio/speech",
+        }
+    }
+
+    fn auth_header(&self, api_key: &str) -> (&'static str, String) {
+        ("Authorization", format!("Bearer {}", api_key))
+    }
+
+    fn body(&self, text: &str, request: &TtsRequest) -> serde_json::Value {
+        match self {
+            TtsProvider::FishAudio => serde_json::json!({
+                "text": text,
+                "reference_id": request.reference_id,
+                "format": request.format
+            }),
+            TtsProvider::OpenAI => serde_json::json!({
+                "model": "tts-1",
+                "voice": request.voice.as_str(),
+                "input": text,
+                "response_format": request.format
+            }),
+        }
+    }
+}
+
+/// 将文本规整空白后，按 `max_len` 贪心切分为若干段，尽量在空格处断开
+fn cut_text(text: &str, max_len: usize) -> Vec<String> {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if normalized.is_empty() {
+        return Vec::new();
+    }
+
+    let max_len = max_len.max(1);
+
+    let chars: Vec<char> = normalized.chars().collect();
+    let mut segments = Vec::new();
+    let mut start = 0;
+
+    while start < chars.len() {
+        if chars.len() - start <= max_len {
+            segments.push(chars[start..].iter().collect::<String>());
+            break;
+        }
+
+        let mut end = start + max_len;
+        let mut split_at = None;
+        while end > start {
+            if chars[end] == ' ' {
+                split_at = Some(end);
+                break;
+            }
+            end -= 1;
+        }
+
+        let cut = split_at.unwrap_or(start + max_len);
+        segments.push(chars[start..cut].iter().collect::<String>());
+        start = if split_at.is_some() { cut + 1 } else { cut };
+    }
+
+    segments
+}
+
 /// TTS 响应
 #[derive(Debug, Serialize)]
 pub struct TtsResponse {
@@ -30,59 +160,593 @@ pub struct TtsResponse {
     pub error: Option<String>,
 }
 
-/// Fish Audio TTS 代理 - 绕过 CORS
+/// 决定 HTTP 客户端行为的配置，相同配置复用同一个 `reqwest::Client`
+#[derive(Debug, Clone, PartialEq, Default)]
+struct ClientConfig {
+    connect_timeout_ms: Option<u64>,
+    read_timeout_ms: Option<u64>,
+    proxy: Option<String>,
+}
+
+impl From<&TtsRequest> for ClientConfig {
+    fn from(request: &TtsRequest) -> Self {
+        ClientConfig {
+            connect_timeout_ms: request.connect_timeout_ms,
+            read_timeout_ms: request.read_timeout_ms,
+            proxy: request.proxy.clone(),
+        }
+    }
+}
+
+/// 缓存的 HTTP 客户端，配置不变时跨调用复用以保留连接池
+#[derive(Default)]
+struct HttpClientState {
+    inner: std::sync::Mutex<Option<(ClientConfig, reqwest::Client)>>,
+}
+
+fn build_client(config: &ClientConfig) -> Result<reqwest::Client, String> {
+    let mut builder = reqwest::ClientBuilder::new();
+    if let Some(ms) = config.connect_timeout_ms {
+        builder = builder.connect_timeout(std::time::Duration::from_millis(ms));
+    }
+    if let Some(ms) = config.read_timeout_ms {
+        builder = builder.read_timeout(std::time::Duration::from_millis(ms));
+    }
+    if let Some(proxy_url) = &config.proxy {
+        let proxy = reqwest::Proxy::all(proxy_url).map_err(|e| format!("代理配置无效: {}", e))?;
+        builder = builder.proxy(proxy);
+    }
+    builder
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))
+}
+
+/// 按给定配置取得一个复用的 `reqwest::Client`，配置变化时才重建
+fn get_client(
+    state: &tauri::State<'_, HttpClientState>,
+    config: ClientConfig,
+) -> Result<reqwest::Client, String> {
+    let mut guard = state.inner.lock().unwrap();
+    if let Some((cached_config, client)) = guard.as_ref() {
+        if *cached_config == config {
+            return Ok(client.clone());
+        }
+    }
+
+    let client = build_client(&config)?;
+    *guard = Some((config, client.clone()));
+    Ok(client)
+}
+
+/// 将 reqwest 错误归类为更具体的失败原因，便于前端区分超时/网络/HTTP 错误
+fn classify_error(e: &reqwest::Error) -> String {
+    if e.is_timeout() {
+        format!("请求超时: {}", e)
+    } else if e.is_connect() {
+        format!("网络连接失败: {}", e)
+    } else if e.is_status() {
+        format!("HTTP错误: {}", e)
+    } else {
+        format!("请求失败: {}", e)
+    }
+}
+
+/// 发送请求，在超时/网络等可重试的失败上按指数退避重试，最多 `max_retries` 次
+async fn send_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+    header_name: &str,
+    header_value: &str,
+    body: &serde_json::Value,
+    max_retries: u32,
+) -> Result<reqwest::Response, String> {
+    let mut attempt = 0;
+    loop {
+        let result = client
+            .post(url)
+            .header(header_name, header_value)
+            .header("Content-Type", "application/json")
+            .json(body)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) => return Ok(response),
+            Err(e) if attempt < max_retries && (e.is_timeout() || e.is_connect()) => {
+                attempt += 1;
+                let backoff = std::time::Duration::from_millis(200 * 2u64.pow(attempt - 1));
+                tokio::time::sleep(backoff).await;
+            }
+            Err(e) => return Err(classify_error(&e)),
+        }
+    }
+}
+
+/// 合成单个文本片段，返回原始音频字节
+async fn synthesize_fragment(
+    client: &reqwest::Client,
+    text: &str,
+    request: &TtsRequest,
+) -> Result<Vec<u8>, String> {
+    let body = request.provider.body(text, request);
+    let (header_name, header_value) = request.provider.auth_header(&request.api_key);
+
+    let response = send_with_retry(
+        client,
+        request.provider.endpoint(),
+        header_name,
+        &header_value,
+        &body,
+        request.max_retries.unwrap_or(0),
+    )
+    .await?;
+
+    if response.status().is_success() {
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| classify_error(&e))
+    } else {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        Err(format!("API错误 {}: {}", status, error_text))
+    }
+}
+
+/// 合成整段文本：按 `max_len` 在空格处切分为片段、逐段请求、按原顺序拼接音频字节
+async fn synthesize(request: &TtsRequest, client: &reqwest::Client) -> Result<Vec<u8>, String> {
+    let fragments = cut_text(&request.text, request.max_len);
+    if fragments.is_empty() {
+        return Err("文本为空".to_string());
+    }
+
+    let mut audio = Vec::new();
+    for fragment in &fragments {
+        let bytes = synthesize_fragment(client, fragment, request).await?;
+        audio.extend(bytes);
+    }
+
+    Ok(audio)
+}
+
+/// TTS 代理 - 绕过 CORS，按 `provider` 选择后端，超长文本会按 `max_len` 在空格处切分（无空格时硬切）后分段合成再拼接
+#[command]
+async fn tts_synthesize(
+    request: TtsRequest,
+    state: tauri::State<'_, HttpClientState>,
+) -> Result<TtsResponse, ()> {
+    let client = match get_client(&state, ClientConfig::from(&request)) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(TtsResponse {
+                success: false,
+                audio_base64: None,
+                error: Some(e),
+            })
+        }
+    };
+
+    Ok(match synthesize(&request, &client).await {
+        Ok(audio) => TtsResponse {
+            success: true,
+            audio_base64: Some(STANDARD.encode(&audio)),
+            error: None,
+        },
+        Err(e) => TtsResponse {
+            success: false,
+            audio_base64: None,
+            error: Some(e),
+        },
+    })
+}
+
+/// TTS 流式响应分片，通过 `Channel` 推送给前端
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TtsChunk {
+    Chunk { audio_base64: String },
+    Done,
+    Error { message: String },
+}
+
+/// TTS 流式代理 - 按 `provider` 选择后端，边接收边推送音频分片，避免等待整段下载完成
+#[command]
+async fn tts_synthesize_stream(
+    request: TtsRequest,
+    channel: Channel<TtsChunk>,
+    state: tauri::State<'_, HttpClientState>,
+) -> Result<TtsResponse, ()> {
+    let client = match get_client(&state, ClientConfig::from(&request)) {
+        Ok(client) => client,
+        Err(e) => {
+            let _ = channel.send(TtsChunk::Error { message: e.clone() });
+            return Ok(TtsResponse {
+                success: false,
+                audio_base64: None,
+                error: Some(e),
+            });
+        }
+    };
+
+    let body = request.provider.body(&request.text, &request);
+    let (header_name, header_value) = request.provider.auth_header(&request.api_key);
+
+    let result = send_with_retry(
+        &client,
+        request.provider.endpoint(),
+        header_name,
+        &header_value,
+        &body,
+        request.max_retries.unwrap_or(0),
+    )
+    .await;
+
+    Ok(match result {
+        Ok(response) => {
+            if response.status().is_success() {
+                let mut stream = response.bytes_stream();
+                while let Some(next) = stream.next().await {
+                    match next {
+                        Ok(bytes) => {
+                            let _ = channel.send(TtsChunk::Chunk {
+                                audio_base64: STANDARD.encode(&bytes),
+                            });
+                        }
+                        Err(e) => {
+                            let message = format!("读取数据块失败: {}", e);
+                            let _ = channel.send(TtsChunk::Error {
+                                message: message.clone(),
+                            });
+                            return Ok(TtsResponse {
+                                success: false,
+                                audio_base64: None,
+                                error: Some(message),
+                            });
+                        }
+                    }
+                }
+                let _ = channel.send(TtsChunk::Done);
+                TtsResponse {
+                    success: true,
+                    audio_base64: None,
+                    error: None,
+                }
+            } else {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_default();
+                let message = format!("API错误 {}: {}", status, error_text);
+                let _ = channel.send(TtsChunk::Error {
+                    message: message.clone(),
+                });
+                TtsResponse {
+                    success: false,
+                    audio_base64: None,
+                    error: Some(message),
+                }
+            }
+        }
+        Err(message) => {
+            let _ = channel.send(TtsChunk::Error {
+                message: message.clone(),
+            });
+            TtsResponse {
+                success: false,
+                audio_base64: None,
+                error: Some(message),
+            }
+        }
+    })
+}
+
+/// 发给播放线程的控制指令
+enum PlaybackCommand {
+    Pause,
+    Stop,
+}
+
+/// 本地播放状态 - `rodio::OutputStream` 在常见后端上不是 `Send`，
+/// 因此真正的输出流与 Sink 都留在专用播放线程内，这里只保存可以跨线程
+/// 共享的指令发送端，供 `tts_play`/`tts_pause`/`tts_stop` 共享
+#[derive(Default)]
+struct PlaybackState {
+    inner: std::sync::Mutex<Option<std::sync::mpsc::Sender<PlaybackCommand>>>,
+}
+
+/// 推送给前端的振幅采样，用于驱动口型动画
+#[derive(Debug, Clone, Serialize)]
+pub struct AmplitudeEvent {
+    pub amplitude: f32,
+}
+
+/// 计算一段 PCM 采样的均方根振幅，归一化到 0.0..=1.0
+fn rms_amplitude(samples: &[i16]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum_sq: f64 = samples.iter().map(|&s| (s as f64) * (s as f64)).sum();
+    ((sum_sq / samples.len() as f64).sqrt() / i16::MAX as f64).min(1.0) as f32
+}
+
+/// 合成并在本地通过 rodio 播放，同时周期性上报振幅供前端驱动口型动画
 #[command]
-async fn tts_synthesize(request: TtsRequest) -> TtsResponse {
-    let client = reqwest::Client::new();
-    
-    let body = serde_json::json!({
-        "text": request.text,
-        "reference_id": request.reference_id,
-        "format": request.format
+async fn tts_play(
+    request: TtsRequest,
+    app: tauri::AppHandle,
+    playback: tauri::State<'_, PlaybackState>,
+    http: tauri::State<'_, HttpClientState>,
+) -> Result<TtsResponse, ()> {
+    use tauri::Emitter;
+
+    let client = match get_client(&http, ClientConfig::from(&request)) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(TtsResponse {
+                success: false,
+                audio_base64: None,
+                error: Some(e),
+            })
+        }
+    };
+
+    let audio = match synthesize(&request, &client).await {
+        Ok(audio) => audio,
+        Err(e) => {
+            return Ok(TtsResponse {
+                success: false,
+                audio_base64: None,
+                error: Some(e),
+            })
+        }
+    };
+
+    let decoder = match rodio::Decoder::new(std::io::Cursor::new(audio)) {
+        Ok(decoder) => decoder,
+        Err(e) => {
+            return Ok(TtsResponse {
+                success: false,
+                audio_base64: None,
+                error: Some(format!("解码音频失败: {}", e)),
+            })
+        }
+    };
+
+    let channels = decoder.channels();
+    let sample_rate = decoder.sample_rate();
+    let samples: Vec<i16> = decoder.collect();
+
+    let (ready_tx, ready_rx) = tokio::sync::oneshot::channel::<Result<(), String>>();
+    let (cmd_tx, cmd_rx) = std::sync::mpsc::channel::<PlaybackCommand>();
+    let playback_samples = samples.clone();
+
+    // `rodio::OutputStream` 不是 `Send`，所以输出流与 Sink 只在这个专用线程内创建和使用；
+    // 线程通过 `cmd_rx` 接收来自 `tts_pause`/`tts_stop` 的指令，播放结束或收到 Stop 后退出。
+    std::thread::spawn(move || {
+        let (stream, stream_handle) = match rodio::OutputStream::try_default() {
+            Ok(pair) => pair,
+            Err(e) => {
+                let _ = ready_tx.send(Err(format!("打开音频输出失败: {}", e)));
+                return;
+            }
+        };
+
+        let sink = match rodio::Sink::try_new(&stream_handle) {
+            Ok(sink) => sink,
+            Err(e) => {
+                let _ = ready_tx.send(Err(format!("创建播放队列失败: {}", e)));
+                return;
+            }
+        };
+
+        sink.append(rodio::buffer::SamplesBuffer::new(
+            channels,
+            sample_rate,
+            playback_samples,
+        ));
+        let _ = ready_tx.send(Ok(()));
+
+        loop {
+            match cmd_rx.recv_timeout(std::time::Duration::from_millis(100)) {
+                Ok(PlaybackCommand::Pause) => sink.pause(),
+                Ok(PlaybackCommand::Stop) => break,
+                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                    if sink.empty() {
+                        break;
+                    }
+                }
+                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        drop(stream);
+    });
+
+    match ready_rx.await {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => {
+            return Ok(TtsResponse {
+                success: false,
+                audio_base64: None,
+                error: Some(e),
+            })
+        }
+        Err(_) => {
+            return Ok(TtsResponse {
+                success: false,
+                audio_base64: None,
+                error: Some("播放线程初始化失败".to_string()),
+            })
+        }
+    }
+
+    *playback.inner.lock().unwrap() = Some(cmd_tx);
+
+    let window = ((sample_rate as usize / 10).max(1)) * channels as usize;
+    tauri::async_runtime::spawn(async move {
+        for chunk in samples.chunks(window) {
+            let _ = app.emit(
+                "tts-amplitude",
+                AmplitudeEvent {
+                    amplitude: rms_amplitude(chunk),
+                },
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        let _ = app.emit("tts-amplitude", AmplitudeEvent { amplitude: 0.0 });
     });
 
+    Ok(TtsResponse {
+        success: true,
+        audio_base64: None,
+        error: None,
+    })
+}
+
+/// 暂停当前正在播放的音频
+#[command]
+fn tts_pause(state: tauri::State<'_, PlaybackState>) {
+    if let Some(tx) = state.inner.lock().unwrap().as_ref() {
+        let _ = tx.send(PlaybackCommand::Pause);
+    }
+}
+
+/// 停止并释放当前的播放队列
+#[command]
+fn tts_stop(state: tauri::State<'_, PlaybackState>) {
+    if let Some(tx) = state.inner.lock().unwrap().take() {
+        let _ = tx.send(PlaybackCommand::Stop);
+    }
+}
+
+/// STT 请求参数，录音以 base64 或临时文件路径任选其一提供
+#[derive(Debug, Deserialize)]
+pub struct SttRequest {
+    pub api_key: String,
+    #[serde(default)]
+    pub audio_base64: Option<String>,
+    #[serde(default)]
+    pub file_path: Option<String>,
+    #[serde(default = "default_stt_model")]
+    pub model: String,
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+fn default_stt_model() -> String {
+    "whisper-1".to_string()
+}
+
+/// STT 响应
+#[derive(Debug, Serialize)]
+pub struct SttResponse {
+    pub success: bool,
+    pub text: Option<String>,
+    pub error: Option<String>,
+}
+
+/// 从 base64 或临时文件路径取得原始录音字节
+fn load_audio(request: &SttRequest) -> Result<Vec<u8>, String> {
+    if let Some(audio_base64) = &request.audio_base64 {
+        return STANDARD
+            .decode(audio_base64)
+            .map_err(|e| format!("解码音频失败: {}", e));
+    }
+    if let Some(file_path) = &request.file_path {
+        return std::fs::read(file_path).map_err(|e| format!("读取音频文件失败: {}", e));
+    }
+    Err("需要提供 audio_base64 或 file_path".to_string())
+}
+
+/// Whisper 语音转文字代理 - 绕过 CORS，让头像也能听懂用户说话
+#[command]
+async fn stt_transcribe(
+    request: SttRequest,
+    http: tauri::State<'_, HttpClientState>,
+) -> Result<SttResponse, ()> {
+    let audio = match load_audio(&request) {
+        Ok(audio) => audio,
+        Err(e) => {
+            return Ok(SttResponse {
+                success: false,
+                text: None,
+                error: Some(e),
+            })
+        }
+    };
+
+    let client = match get_client(&http, ClientConfig::default()) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(SttResponse {
+                success: false,
+                text: None,
+                error: Some(e),
+            })
+        }
+    };
+
+    let part = match reqwest::multipart::Part::bytes(audio)
+        .file_name("audio.wav")
+        .mime_str("audio/wav")
+    {
+        Ok(part) => part,
+        Err(e) => {
+            return Ok(SttResponse {
+                success: false,
+                text: None,
+                error: Some(format!("构建音频分片失败: {}", e)),
+            })
+        }
+    };
+
+    let mut form = reqwest::multipart::Form::new()
+        .part("file", part)
+        .text("model", request.model.clone());
+    if let Some(language) = &request.language {
+        form = form.text("language", language.clone());
+    }
+
     let result = client
-        .post("https://api.fish.audio/v1/tts")
+        .post("https://api.openai.com/v1/audio/transcriptions")
         .header("Authorization", format!("Bearer {}", request.api_key))
-        .header("Content-Type", "application/json")
-        .json(&body)
+        .multipart(form)
         .send()
         .await;
 
-    match result {
+    Ok(match result {
         Ok(response) => {
             if response.status().is_success() {
-                match response.bytes().await {
-                    Ok(bytes) => {
-                        let audio_base64 = STANDARD.encode(&bytes);
-                        TtsResponse {
-                            success: true,
-                            audio_base64: Some(audio_base64),
-                            error: None,
-                        }
-                    }
-                    Err(e) => TtsResponse {
+                match response.json::<serde_json::Value>().await {
+                    Ok(json) => SttResponse {
+                        success: true,
+                        text: json
+                            .get("text")
+                            .and_then(|v| v.as_str())
+                            .map(|s| s.to_string()),
+                        error: None,
+                    },
+                    Err(e) => SttResponse {
                         success: false,
-                        audio_base64: None,
-                        error: Some(format!("读取响应失败: {}", e)),
+                        text: None,
+                        error: Some(format!("解析响应失败: {}", e)),
                     },
                 }
             } else {
                 let status = response.status();
                 let error_text = response.text().await.unwrap_or_default();
-                TtsResponse {
+                SttResponse {
                     success: false,
-                    audio_base64: None,
+                    text: None,
                     error: Some(format!("API错误 {}: {}", status, error_text)),
                 }
             }
         }
-        Err(e) => TtsResponse {
+        Err(e) => SttResponse {
             success: false,
-            audio_base64: None,
-            error: Some(format!("请求失败: {}", e)),
+            text: None,
+            error: Some(classify_error(&e)),
         },
-    }
+    })
 }
 
 #[command]
@@ -94,7 +758,17 @@ fn greet(name: &str) -> String {
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![greet, tts_synthesize])
+        .manage(PlaybackState::default())
+        .manage(HttpClientState::default())
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            tts_synthesize,
+            tts_synthesize_stream,
+            tts_play,
+            tts_pause,
+            tts_stop,
+            stt_transcribe
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }